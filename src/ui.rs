@@ -2,7 +2,9 @@ use crate::poller::StatsReceiver;
 use crate::stats::{ConnectionStats, Stats, StatsValue};
 use clap::Parser;
 use std::collections::{BTreeMap, HashSet, VecDeque};
-use std::time::{Duration, Instant};
+use std::sync::mpsc;
+use std::thread;
+use std::time::{Duration, Instant, SystemTime, UNIX_EPOCH};
 
 #[derive(Debug, Parser)]
 pub struct UiOpts {
@@ -11,6 +13,169 @@ pub struct UiOpts {
 
     #[clap(long, default_value = "total=.*:.*")]
     pub tab: Vec<Tab>,
+
+    #[clap(long, default_value_t = 250)]
+    pub tick_rate: u64,
+
+    #[clap(long, default_value = "csv")]
+    pub export_format: ExportFormat,
+}
+
+#[derive(Debug, Clone, Copy)]
+pub enum ExportFormat {
+    Csv,
+    Json,
+}
+
+impl ExportFormat {
+    fn extension(&self) -> &'static str {
+        match self {
+            Self::Csv => "csv",
+            Self::Json => "json",
+        }
+    }
+}
+
+impl std::str::FromStr for ExportFormat {
+    type Err = anyhow::Error;
+
+    fn from_str(s: &str) -> Result<Self, Self::Err> {
+        match s {
+            "csv" => Ok(Self::Csv),
+            "json" => Ok(Self::Json),
+            _ => anyhow::bail!("invalid export format {s:?} (expected \"csv\" or \"json\")"),
+        }
+    }
+}
+
+/// An event fed into `App::run`'s select loop: a piece of terminal input, a
+/// regular tick used to pace redraws independently of input, a batch of
+/// freshly polled Sora stats, or the stats poller going away.
+enum Event<I> {
+    Input(I),
+    Tick,
+    Stats(Vec<ConnectionStats>),
+    StatsDisconnected,
+}
+
+/// Spawn the threads that drive `App::run`'s merged select loop: one polls
+/// crossterm for input and forwards it as `Event::Input`, emitting
+/// `Event::Tick` every `tick_rate` regardless of whether input arrived; the
+/// other forwards `rx` (the Sora stats poller) as `Event::Stats`, sending
+/// `Event::StatsDisconnected` once the poller hangs up. Both feed the same
+/// channel so `App::run` can `recv` a single merged stream.
+fn spawn_event_thread(
+    tick_rate: Duration,
+    rx: StatsReceiver,
+) -> mpsc::Receiver<Event<crossterm::event::Event>> {
+    let (tx, events) = mpsc::channel();
+
+    let input_tx = tx.clone();
+    thread::spawn(move || {
+        let mut last_tick = Instant::now();
+        loop {
+            let timeout = tick_rate
+                .checked_sub(last_tick.elapsed())
+                .unwrap_or_else(|| Duration::from_secs(0));
+            if crossterm::event::poll(timeout).unwrap_or(false) {
+                match crossterm::event::read() {
+                    Ok(event) => {
+                        if input_tx.send(Event::Input(event)).is_err() {
+                            return;
+                        }
+                    }
+                    Err(e) => {
+                        log::warn!("failed to read terminal event: {e}");
+                    }
+                }
+            }
+            if last_tick.elapsed() >= tick_rate {
+                if input_tx.send(Event::Tick).is_err() {
+                    return;
+                }
+                last_tick = Instant::now();
+            }
+        }
+    });
+
+    thread::spawn(move || loop {
+        match rx.recv() {
+            Ok(connections) => {
+                if tx.send(Event::Stats(connections)).is_err() {
+                    return;
+                }
+            }
+            Err(_) => {
+                let _ = tx.send(Event::StatsDisconnected);
+                return;
+            }
+        }
+    });
+
+    events
+}
+
+const LOG_BUFFER_CAPACITY: usize = 200;
+
+/// A bounded, shareable ring of formatted log records so the UI can render
+/// recent `log::debug!`/`log::warn!` output while the alternate screen is
+/// active.
+#[derive(Debug, Clone, Default)]
+struct LogBuffer(std::sync::Arc<std::sync::Mutex<VecDeque<String>>>);
+
+impl LogBuffer {
+    fn push(&self, record: String) {
+        let mut buf = self.0.lock().expect("log buffer poisoned");
+        buf.push_back(record);
+        while buf.len() > LOG_BUFFER_CAPACITY {
+            buf.pop_front();
+        }
+    }
+
+    fn snapshot(&self) -> Vec<String> {
+        self.0
+            .lock()
+            .expect("log buffer poisoned")
+            .iter()
+            .cloned()
+            .collect()
+    }
+}
+
+struct TuiLogger {
+    buffer: LogBuffer,
+}
+
+impl log::Log for TuiLogger {
+    fn enabled(&self, _metadata: &log::Metadata) -> bool {
+        true
+    }
+
+    fn log(&self, record: &log::Record) {
+        if self.enabled(record.metadata()) {
+            self.buffer.push(format!(
+                "[{}] {}: {}",
+                record.level(),
+                record.target(),
+                record.args()
+            ));
+        }
+    }
+
+    fn flush(&self) {}
+}
+
+/// Install the process-wide logger that feeds the in-TUI log panel,
+/// returning the buffer it writes to.
+fn install_logger(level: log::LevelFilter) -> LogBuffer {
+    let buffer = LogBuffer::default();
+    let logger = TuiLogger {
+        buffer: buffer.clone(),
+    };
+    if log::set_logger(Box::leak(Box::new(logger))).is_ok() {
+        log::set_max_level(level);
+    }
+    buffer
 }
 
 #[derive(Debug, Clone)]
@@ -57,6 +222,19 @@ type Terminal = tui::Terminal<tui::backend::CrosstermBackend<std::io::Stdout>>;
 
 type Frame<'a> = tui::Frame<'a, tui::backend::CrosstermBackend<std::io::Stdout>>;
 
+fn point_in_rect(rect: tui::layout::Rect, x: u16, y: u16) -> bool {
+    x >= rect.x && x < rect.x + rect.width && y >= rect.y && y < rect.y + rect.height
+}
+
+/// Quote a CSV field if it contains a comma, quote, or newline.
+fn csv_field(s: &str) -> String {
+    if s.contains(',') || s.contains('"') || s.contains('\n') {
+        format!("\"{}\"", s.replace('"', "\"\""))
+    } else {
+        s.to_string()
+    }
+}
+
 // TODO: rename
 #[derive(Debug)]
 pub struct Ui {
@@ -64,20 +242,71 @@ pub struct Ui {
     history: VecDeque<HistoryItem>,
     tab_index: usize,
     table_state: tui::widgets::TableState,
+    tabs_area: tui::layout::Rect,
+    stats_table_area: tui::layout::Rect,
+    log_buffer: LogBuffer,
+    show_log: bool,
+    input_mode: bool,
+    query: String,
 }
 
 impl Ui {
-    fn new(opt: UiOpts) -> Self {
+    fn new(opt: UiOpts, log_buffer: LogBuffer) -> Self {
         Self {
             opt,
             history: VecDeque::new(),
             tab_index: 0,
             table_state: Default::default(),
+            tabs_area: Default::default(),
+            stats_table_area: Default::default(),
+            log_buffer,
+            show_log: false,
+            input_mode: false,
+            query: String::new(),
         }
     }
 
+    fn tab_at(&self, x: u16) -> Option<usize> {
+        let inner = self.tabs_area.inner(&tui::layout::Margin {
+            vertical: 1,
+            horizontal: 1,
+        });
+        if inner.width == 0 || x < inner.left() || x >= inner.right() {
+            return None;
+        }
+
+        // Mirrors `tui::widgets::Tabs`'s own render loop: a 1-column pad
+        // before each title, then the title at its natural width, then a
+        // 1-column trailing pad and a 1-column divider before the next
+        // title's pad.
+        let mut cursor = inner.left();
+        for (i, tab) in self.opt.tab.iter().enumerate() {
+            cursor = cursor.saturating_add(1);
+            if cursor >= inner.right() {
+                break;
+            }
+            let width = tab.name.chars().count() as u16;
+            let end = cursor.saturating_add(width).min(inner.right());
+            if x >= cursor && x < end {
+                return Some(i);
+            }
+            cursor = end.saturating_add(2); // trailing pad + divider
+        }
+        None
+    }
+
     fn draw(&mut self, f: &mut Frame) {
         use tui::layout::{Constraint, Direction, Layout};
+        use tui::widgets::{Block, Borders, Paragraph};
+
+        if self.history.is_empty() {
+            let block = Block::default().borders(Borders::ALL).title("sorastats");
+            f.render_widget(
+                Paragraph::new("waiting for stats...").block(block),
+                f.size(),
+            );
+            return;
+        }
 
         let chunks = Layout::default()
             .direction(Direction::Vertical)
@@ -94,7 +323,11 @@ impl Ui {
 
         self.draw_tabs(f, chunks[0]);
         self.draw_stats(f, chunks[1], self.opt.tab[self.tab_index].clone());
-        self.draw_help(f, chunks[2]);
+        if self.show_log {
+            self.draw_log(f, chunks[2]);
+        } else {
+            self.draw_help(f, chunks[2]);
+        }
     }
 
     fn draw_stats(&mut self, f: &mut Frame, area: tui::layout::Rect, tab: Tab) {
@@ -109,11 +342,30 @@ impl Ui {
         self.draw_detailed_stats(f, chunks[1], &tab);
     }
 
+    /// The compiled query regex, if the query is non-empty and a valid
+    /// pattern, and `None` otherwise; `latest_stats` then falls back to a
+    /// plain substring match.
+    fn query_regex(&self) -> Option<regex::Regex> {
+        if self.query.is_empty() {
+            return None;
+        }
+        regex::Regex::new(&self.query).ok()
+    }
+
     fn latest_stats(&self, tab: &Tab) -> Vec<StatsItem> {
+        let query_re = self.query_regex();
+        let matches_query = |k: &str| match &query_re {
+            Some(re) => re.is_match(k),
+            None => self.query.is_empty() || k.contains(self.query.as_str()),
+        };
+
         let mut items = BTreeMap::<_, StatsItem>::new();
         for conn in &self.history.back().expect("unreachable").connections {
             if tab.is_match(&conn.stats) {
                 for (k, v) in &conn.stats {
+                    if !matches_query(k) {
+                        continue;
+                    }
                     let entry = items.entry(k).or_default();
                     entry.key = k.clone();
                     entry.values.insert(v.clone());
@@ -128,6 +380,8 @@ impl Ui {
         use tui::style::{Color, Modifier, Style};
         use tui::widgets::{Block, Borders, Cell, Row, Table};
 
+        self.stats_table_area = area;
+
         let selected_style = Style::default().add_modifier(Modifier::REVERSED);
         let normal_style = Style::default().bg(Color::Blue);
 
@@ -140,6 +394,14 @@ impl Ui {
             .bottom_margin(1);
 
         let items = self.latest_stats(tab);
+        if let Some(i) = self.table_state.selected() {
+            if items.is_empty() {
+                self.table_state.select(None);
+            } else if i >= items.len() {
+                self.table_state.select(Some(items.len() - 1));
+            }
+        }
+
         let rows = items.into_iter().map(|item| {
             let cells = match item.aggregated_value() {
                 Ok(sum) => {
@@ -178,13 +440,123 @@ impl Ui {
         f.render_stateful_widget(t, area, &mut self.table_state);
     }
 
+    fn selected_key(&self, tab: &Tab) -> Option<String> {
+        let items = self.latest_stats(tab);
+        let i = self.table_state.selected()?;
+        items.get(i).map(|item| item.key.clone())
+    }
+
+    fn history_series(&self, tab: &Tab, key: &str) -> Result<Vec<(f64, f64)>, usize> {
+        let newest = self.history.back().expect("unreachable").timestamp;
+
+        let mut uniq = 0;
+        let mut points = Vec::with_capacity(self.history.len());
+        for item in &self.history {
+            let mut values = HashSet::new();
+            for conn in &item.connections {
+                if tab.is_match(&conn.stats) {
+                    if let Some(v) = conn
+                        .stats
+                        .iter()
+                        .find(|(k, _)| k.as_str() == key)
+                        .map(|(_, v)| v)
+                    {
+                        values.insert(v.clone());
+                    }
+                }
+            }
+
+            let stats_item = StatsItem {
+                key: key.to_string(),
+                values,
+            };
+            match stats_item.aggregated_value() {
+                Ok(sum) => {
+                    let x = -(newest - item.timestamp).as_secs_f64();
+                    points.push((x, sum));
+                }
+                Err(n) => uniq = uniq.max(n),
+            }
+        }
+
+        if points.is_empty() && uniq > 0 {
+            Err(uniq)
+        } else {
+            Ok(points)
+        }
+    }
+
     fn draw_detailed_stats(&mut self, f: &mut Frame, area: tui::layout::Rect, tab: &Tab) {
-        use tui::widgets::{Block, Borders};
+        use tui::style::{Color, Style};
+        use tui::symbols;
+        use tui::text::Span;
+        use tui::widgets::{Axis, Block, Borders, Chart, Dataset, GraphType, Paragraph};
 
         let block = Block::default()
             .borders(Borders::ALL)
             .title("Detailed Stats");
-        f.render_widget(block, area);
+
+        let key = match self.selected_key(tab) {
+            Some(key) => key,
+            None => {
+                let paragraph = Paragraph::new("select a key in Aggregated Stats").block(block);
+                f.render_widget(paragraph, area);
+                return;
+            }
+        };
+
+        let data = match self.history_series(tab, &key) {
+            Ok(data) => data,
+            Err(_) => {
+                let paragraph = Paragraph::new(format!("{key} is not numeric")).block(block);
+                f.render_widget(paragraph, area);
+                return;
+            }
+        };
+
+        let y_min = data.iter().map(|(_, y)| *y).fold(f64::INFINITY, f64::min);
+        let y_max = data
+            .iter()
+            .map(|(_, y)| *y)
+            .fold(f64::NEG_INFINITY, f64::max);
+        let (y_min, y_max) = if y_min.is_finite() && y_max.is_finite() {
+            (y_min, y_max)
+        } else {
+            (0.0, 0.0)
+        };
+        let margin = ((y_max - y_min) * 0.1).max(1.0);
+        let (y_min, y_max) = (y_min - margin, y_max + margin);
+
+        let datasets = vec![Dataset::default()
+            .name(key.clone())
+            .marker(symbols::Marker::Braille)
+            .graph_type(GraphType::Line)
+            .style(Style::default().fg(Color::Cyan))
+            .data(&data)];
+
+        let chart = Chart::new(datasets)
+            .block(block.title(format!("Detailed Stats: {key}")))
+            .x_axis(
+                Axis::default()
+                    .title("time (s)")
+                    .style(Style::default().fg(Color::Gray))
+                    .bounds([-self.opt.retention_period, 0.0])
+                    .labels(vec![
+                        Span::raw(format!("-{}", self.opt.retention_period)),
+                        Span::raw("0"),
+                    ]),
+            )
+            .y_axis(
+                Axis::default()
+                    .title("value")
+                    .style(Style::default().fg(Color::Gray))
+                    .bounds([y_min, y_max])
+                    .labels(vec![
+                        Span::raw(format!("{y_min:.1}")),
+                        Span::raw(format!("{y_max:.1}")),
+                    ]),
+            );
+        f.render_widget(chart, area);
     }
 
     fn draw_tabs(&mut self, f: &mut Frame, area: tui::layout::Rect) {
@@ -192,6 +564,8 @@ impl Ui {
         use tui::text::Spans;
         use tui::widgets::{Block, Borders, Tabs};
 
+        self.tabs_area = area;
+
         let tabs = Tabs::new(
             self.opt
                 .tab
@@ -211,115 +585,272 @@ impl Ui {
     }
 
     fn draw_help(&mut self, f: &mut Frame, area: tui::layout::Rect) {
-        use tui::widgets::{Block, Borders};
+        use tui::widgets::{Block, Borders, Paragraph};
 
         let block = Block::default().borders(Borders::ALL).title("Help");
-        f.render_widget(block, area);
+        if self.input_mode || !self.query.is_empty() {
+            let text = format!("/{}", self.query);
+            f.render_widget(Paragraph::new(text).block(block), area);
+        } else {
+            f.render_widget(block, area);
+        }
+    }
+
+    fn draw_log(&mut self, f: &mut Frame, area: tui::layout::Rect) {
+        use tui::text::{Span, Spans};
+        use tui::widgets::{Block, Borders, List, ListItem};
+
+        let records = self.log_buffer.snapshot();
+        let visible = area.height.saturating_sub(2) as usize;
+        let items: Vec<ListItem> = records
+            .iter()
+            .rev()
+            .take(visible.max(1))
+            .rev()
+            .map(|line| ListItem::new(Spans::from(Span::raw(line.clone()))))
+            .collect();
+
+        let block = Block::default()
+            .borders(Borders::ALL)
+            .title("Log (l to hide)");
+        f.render_widget(List::new(items).block(block), area);
     }
 }
 
 pub struct App {
-    rx: StatsReceiver,
+    events: mpsc::Receiver<Event<crossterm::event::Event>>,
     terminal: Terminal,
     ui: Ui,
 }
 
 impl App {
     pub fn new(rx: StatsReceiver, opt: UiOpts) -> anyhow::Result<Self> {
+        let log_buffer = install_logger(log::LevelFilter::Debug);
         let terminal = Self::setup_terminal()?;
         log::debug!("setup terminal");
+        let events = spawn_event_thread(Duration::from_millis(opt.tick_rate), rx);
         Ok(Self {
-            rx,
-            ui: Ui::new(opt),
+            events,
+            ui: Ui::new(opt, log_buffer),
             terminal,
         })
     }
 
     pub fn run(mut self) -> anyhow::Result<()> {
         loop {
-            if self.handle_key_event()? {
-                break;
+            match self.events.recv() {
+                Ok(Event::Input(event)) => {
+                    if self.handle_input(event)? {
+                        break;
+                    }
+                }
+                Ok(Event::Tick) => {}
+                Ok(Event::Stats(connections)) => {
+                    self.ingest_stats(connections);
+                }
+                Ok(Event::StatsDisconnected) => {
+                    anyhow::bail!("Sora stats polling thread terminated unexpectedly");
+                }
+                Err(_) => {
+                    anyhow::bail!("event thread terminated unexpectedly");
+                }
             }
-            self.handle_stats_poll()?;
+            self.terminal.draw(|f| self.ui.draw(f))?;
         }
         Ok(())
     }
 
-    fn handle_key_event(&mut self) -> anyhow::Result<bool> {
-        if crossterm::event::poll(std::time::Duration::from_secs(0))? {
-            // TODO: handle resize event
-            if let crossterm::event::Event::Key(key) = crossterm::event::read()? {
+    fn handle_input(&mut self, event: crossterm::event::Event) -> anyhow::Result<bool> {
+        match event {
+            crossterm::event::Event::Key(key) if self.ui.input_mode => {
+                self.handle_search_key(key);
+            }
+            crossterm::event::Event::Key(key) => {
                 use crossterm::event::KeyCode;
                 match key.code {
                     KeyCode::Char('q') => {
                         return Ok(true);
                     }
+                    KeyCode::Char('l') => {
+                        self.ui.show_log = !self.ui.show_log;
+                    }
+                    KeyCode::Char('s') => {
+                        if let Err(e) = self.export_snapshot() {
+                            log::warn!("failed to export stats snapshot: {e}");
+                        }
+                    }
+                    KeyCode::Char('/') => {
+                        self.ui.input_mode = true;
+                    }
                     KeyCode::Right => {
-                        let tab_index =
+                        self.ui.tab_index =
                             std::cmp::min(self.ui.tab_index + 1, self.ui.opt.tab.len() - 1);
-                        if tab_index != self.ui.tab_index {
-                            self.ui.tab_index = tab_index;
-                            self.terminal.draw(|f| self.ui.draw(f))?;
-                        }
                     }
                     KeyCode::Left => {
-                        let tab_index = self.ui.tab_index.saturating_sub(1);
-                        if tab_index != self.ui.tab_index {
-                            self.ui.tab_index = tab_index;
-                            self.terminal.draw(|f| self.ui.draw(f))?;
-                        }
+                        self.ui.tab_index = self.ui.tab_index.saturating_sub(1);
                     }
                     KeyCode::Up => {
-                        let i = if let Some(i) = self.ui.table_state.selected() {
-                            i.saturating_sub(1)
-                        } else {
-                            0
-                        };
+                        let i = self
+                            .ui
+                            .table_state
+                            .selected()
+                            .map_or(0, |i| i.saturating_sub(1));
                         self.ui.table_state.select(Some(i));
-                        self.terminal.draw(|f| self.ui.draw(f))?;
                     }
                     KeyCode::Down => {
-                        let i = if let Some(i) = self.ui.table_state.selected() {
-                            // TODO: min
-                            i + 1
-                        } else {
-                            0
-                        };
+                        // TODO: min
+                        let i = self.ui.table_state.selected().map_or(0, |i| i + 1);
                         self.ui.table_state.select(Some(i));
-                        self.terminal.draw(|f| self.ui.draw(f))?;
                     }
                     _ => {}
                 }
             }
+            crossterm::event::Event::Mouse(mouse) => self.handle_mouse(mouse),
+            // TODO: handle resize event
+            _ => {}
         }
         Ok(false)
     }
 
-    fn handle_stats_poll(&mut self) -> anyhow::Result<()> {
-        match self.rx.recv_timeout(Duration::from_millis(10)) {
-            Err(std::sync::mpsc::RecvTimeoutError::Disconnected) => {
-                anyhow::bail!("Sora stats polling thread terminated unexpectedly");
+    fn handle_search_key(&mut self, key: crossterm::event::KeyEvent) {
+        use crossterm::event::KeyCode;
+
+        match key.code {
+            KeyCode::Char(c) => {
+                self.ui.query.push(c);
             }
-            Err(std::sync::mpsc::RecvTimeoutError::Timeout) => {}
-            Ok(connections) => {
-                log::debug!("recv new stats");
-                self.ui.history.push_back(HistoryItem {
-                    timestamp: Instant::now(),
-                    connections,
-                });
-                while let Some(item) = self.ui.history.pop_front() {
-                    if item.timestamp.elapsed().as_secs_f64() < self.ui.opt.retention_period {
-                        self.ui.history.push_front(item);
-                        break;
-                    }
-                    log::debug!("remove old stats");
+            KeyCode::Backspace => {
+                self.ui.query.pop();
+            }
+            KeyCode::Esc => {
+                self.ui.query.clear();
+                self.ui.input_mode = false;
+            }
+            KeyCode::Enter => {
+                self.ui.input_mode = false;
+            }
+            _ => {}
+        }
+    }
+
+    /// Serialize the entire retained history to a timestamped file in the
+    /// active `--export-format`, restricted to the active tab's matching
+    /// stats.
+    fn export_snapshot(&self) -> anyhow::Result<()> {
+        if self.ui.history.is_empty() {
+            log::warn!("no stats received yet, nothing to export");
+            return Ok(());
+        }
+
+        let timestamp = SystemTime::now().duration_since(UNIX_EPOCH)?.as_secs();
+        let format = self.ui.opt.export_format;
+        let path =
+            std::path::PathBuf::from(format!("sorastats-{timestamp}.{}", format.extension()));
+
+        match format {
+            ExportFormat::Csv => self.export_csv(&path)?,
+            ExportFormat::Json => self.export_json(&path)?,
+        }
+        log::debug!("exported stats snapshot to {}", path.display());
+        Ok(())
+    }
+
+    fn export_csv(&self, path: &std::path::Path) -> anyhow::Result<()> {
+        let tab = &self.ui.opt.tab[self.ui.tab_index];
+        let newest = self.ui.history.back().expect("unreachable").timestamp;
+
+        let mut out = String::from("timestamp,connection_index,key,value\n");
+        for item in &self.ui.history {
+            let offset = -(newest - item.timestamp).as_secs_f64();
+            for (i, conn) in item.connections.iter().enumerate() {
+                if !tab.is_match(&conn.stats) {
+                    continue;
+                }
+                for (k, v) in &conn.stats {
+                    out.push_str(&format!(
+                        "{offset},{i},{},{}\n",
+                        csv_field(k),
+                        csv_field(&v.to_string())
+                    ));
                 }
-                self.terminal.draw(|f| self.ui.draw(f))?;
             }
         }
+        std::fs::write(path, out)?;
         Ok(())
     }
 
+    fn export_json(&self, path: &std::path::Path) -> anyhow::Result<()> {
+        let tab = &self.ui.opt.tab[self.ui.tab_index];
+        let newest = self.ui.history.back().expect("unreachable").timestamp;
+
+        let snapshots: Vec<serde_json::Value> = self
+            .ui
+            .history
+            .iter()
+            .map(|item| {
+                let offset = -(newest - item.timestamp).as_secs_f64();
+                let connections: Vec<&Stats> = item
+                    .connections
+                    .iter()
+                    .filter(|conn| tab.is_match(&conn.stats))
+                    .map(|conn| &conn.stats)
+                    .collect();
+                serde_json::json!({ "timestamp": offset, "connections": connections })
+            })
+            .collect();
+
+        let file = std::fs::File::create(path)?;
+        serde_json::to_writer_pretty(file, &snapshots)?;
+        Ok(())
+    }
+
+    fn handle_mouse(&mut self, event: crossterm::event::MouseEvent) {
+        use crossterm::event::{MouseButton, MouseEventKind};
+
+        match event.kind {
+            MouseEventKind::ScrollUp
+                if point_in_rect(self.ui.stats_table_area, event.column, event.row) =>
+            {
+                let i = self
+                    .ui
+                    .table_state
+                    .selected()
+                    .map_or(0, |i| i.saturating_sub(1));
+                self.ui.table_state.select(Some(i));
+            }
+            MouseEventKind::ScrollDown
+                if point_in_rect(self.ui.stats_table_area, event.column, event.row) =>
+            {
+                // TODO: min
+                let i = self.ui.table_state.selected().map_or(0, |i| i + 1);
+                self.ui.table_state.select(Some(i));
+            }
+            MouseEventKind::Down(MouseButton::Left)
+                if point_in_rect(self.ui.tabs_area, event.column, event.row) =>
+            {
+                if let Some(i) = self.ui.tab_at(event.column) {
+                    self.ui.tab_index = i;
+                }
+            }
+            _ => {}
+        }
+    }
+
+    fn ingest_stats(&mut self, connections: Vec<ConnectionStats>) {
+        log::debug!("recv new stats");
+        self.ui.history.push_back(HistoryItem {
+            timestamp: Instant::now(),
+            connections,
+        });
+        while let Some(item) = self.ui.history.pop_front() {
+            if item.timestamp.elapsed().as_secs_f64() < self.ui.opt.retention_period {
+                self.ui.history.push_front(item);
+                break;
+            }
+            log::debug!("remove old stats");
+        }
+    }
+
     fn setup_terminal() -> anyhow::Result<Terminal> {
         crossterm::terminal::enable_raw_mode()?;
         let mut stdout = std::io::stdout();